@@ -1,16 +1,22 @@
 use rand::Rng;
 use rand::seq::SliceRandom;
+use rayon::prelude::*;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::io::{self, BufRead, Write};
 use std::time::Instant;
-use std::{fmt, mem, str};
+use std::{fmt, str};
 
 pub const MAX_WORD_LEN: u8 = 30;
 
 /// A data structure that compactly stores the word list.
 struct WordList {
     word_data: Vec<Vec<u8>>,
+    /// For each length `L` (indexed as `word_data`) and each word of that
+    /// length, a bitmask per letter giving the positions at which that
+    /// letter occurs. Since `MAX_WORD_LEN <= 32`, a `u32` is always wide
+    /// enough to hold every position.
+    position_masks: Vec<Vec<[u32; 26]>>,
     total_words: usize,
 }
 
@@ -41,14 +47,29 @@ impl WordList {
     fn new() -> Self {
         Self {
             word_data: Vec::new(),
+            position_masks: Vec::new(),
             total_words: 0,
         }
     }
     
-    /// Count the number of words with a given length.
+    /// Count the number of words with a given length. Returns `0` for a
+    /// length no word in the list has, so this is safe to call with
+    /// user-supplied lengths.
     fn count_with_length(&self, len: u8) -> usize {
         let len = len as usize;
-        self.word_data[len - 1].len() / len
+        len.checked_sub(1)
+            .and_then(|i| self.word_data.get(i))
+            .map_or(0, |data| data.len() / len)
+    }
+
+    /// Return the word length shared by the greatest number of words.
+    fn most_common_length(&self) -> u8 {
+        self.word_data.iter()
+            .enumerate()
+            .map(|(i, data_vec)| ((i + 1) as u8, data_vec.len() / (i + 1)))
+            .max_by_key(|&(_, count)| count)
+            .unwrap()
+            .0
     }
 
     /// Add a word to the list and return its index.
@@ -57,13 +78,17 @@ impl WordList {
         assert!(0 < len && len < (MAX_WORD_LEN as usize));
         while self.word_data.len() < len {
             self.word_data.push(Vec::new());
+            self.position_masks.push(Vec::new());
         }
         let data_vec = &mut self.word_data[len - 1];
         let idx = data_vec.len() / len;
-        for b in word.bytes() {
+        let mut masks = [0u32; 26];
+        for (i, b) in word.bytes().enumerate() {
             assert!(b.is_ascii_lowercase());
             data_vec.push(b);
+            masks[(b - b'a') as usize] |= 1 << i;
         }
+        self.position_masks[len - 1].push(masks);
         self.total_words += 1;
         Word {
             len: len as u8,
@@ -78,6 +103,12 @@ impl WordList {
         &self.word_data[len - 1][len * idx .. len * (idx + 1)]
     }
 
+    /// Return a bitmask of the positions at which `letter` occurs in `word`.
+    fn position_mask(&self, word: Word, letter: u8) -> u32 {
+        let Word { len, idx } = word;
+        self.position_masks[len as usize - 1][idx][(letter - b'a') as usize]
+    }
+
     /// Return the index of a random word.
     fn random<R: Rng>(&self, rng: &mut R) -> Word {
         let idx = rng.gen_range(0, self.total_words);
@@ -159,6 +190,303 @@ impl Executioner for HonestExecutioner {
     fn wrong_guesses(&self) -> usize { self.wrong_guesses }
 }
 
+/// An executioner that never commits to a word, instead answering each guess
+/// however is worst for the guesser. It maintains the set of words still
+/// consistent with every answer given so far, and on each guess picks
+/// whichever answer keeps the most candidates alive.
+struct AdversarialExecutioner {
+    word_len: u8,
+    candidates: HashSet<usize>,
+    wrong_guesses: usize,
+}
+
+impl AdversarialExecutioner {
+    fn with_candidates(word_len: u8, candidates: HashSet<usize>) -> Self {
+        Self { word_len, candidates, wrong_guesses: 0 }
+    }
+}
+
+impl Executioner for AdversarialExecutioner {
+    fn init(word: Word, words: &WordList) -> Self {
+        let word_len = word.len;
+        let candidates = (0..words.count_with_length(word_len)).collect();
+        Self::with_candidates(word_len, candidates)
+    }
+
+    fn choose<R: Rng>(words: &WordList, _rng: &mut R) -> Self {
+        let word_len = words.most_common_length();
+        let candidates = (0..words.count_with_length(word_len)).collect();
+        Self::with_candidates(word_len, candidates)
+    }
+
+    fn guess(&mut self, words: &WordList, letter: u8, idxs: &mut Vec<u8>) {
+        // Partition the candidates by the exact position mask `letter` would
+        // reveal, then keep whichever bucket is largest, favoring the
+        // "letter absent" bucket (mask `0`) on ties so the guess scores as wrong.
+        let mut buckets: HashMap<u32, HashSet<usize>> = HashMap::new();
+        for &idx in &self.candidates {
+            let word = Word { len: self.word_len, idx };
+            let mask = words.position_mask(word, letter);
+            buckets.entry(mask).or_default().insert(idx);
+        }
+
+        let (mask, bucket) = buckets.into_iter()
+            .max_by_key(|(mask, bucket)| (bucket.len(), *mask == 0))
+            .unwrap();
+
+        self.candidates = bucket;
+        idxs.clear();
+        idxs.extend((0..self.word_len).filter(|&i| mask & (1 << i) != 0));
+        if idxs.is_empty() {
+            self.wrong_guesses += 1;
+        }
+    }
+
+    fn word_len(&self) -> u8 { self.word_len }
+    fn wrong_guesses(&self) -> usize { self.wrong_guesses }
+}
+
+/// An executioner backed by a human player in an actual game of hangman.
+/// `guess` prints the suggested letter and the word as currently known, then
+/// reads back where (if anywhere) the player's opponent revealed it.
+///
+/// Unlike the other executioners, this one also picks the letter to suggest
+/// (see `suggest_letter`) and owns the only candidate set in play, so that
+/// `undo` has a single, consistent piece of state to roll back instead of
+/// leaving some other candidate-narrowing strategy stuck with a stale copy.
+struct InteractiveExecutioner {
+    word_len: u8,
+    revealed: Vec<Option<u8>>,
+    candidates: HashSet<usize>,
+    history: Vec<(u8, Vec<u8>)>,
+    wrong_guesses: usize,
+    /// Set once `read_interactive_line` hits end-of-input, so the caller can
+    /// stop prompting instead of treating the missing answer as a real one.
+    quit: bool,
+}
+
+impl InteractiveExecutioner {
+    fn new(word_len: u8, words: &WordList) -> Self {
+        Self {
+            word_len,
+            revealed: vec![None; word_len as usize],
+            candidates: (0..words.count_with_length(word_len)).collect(),
+            history: Vec::new(),
+            wrong_guesses: 0,
+            quit: false,
+        }
+    }
+
+    fn print_state(&self) {
+        let blank: String = self.revealed.iter()
+            .map(|c| c.map_or('_', |c| c as char))
+            .collect();
+        println!("  word so far: {}", blank);
+    }
+
+    /// Recompute `candidates` from scratch by replaying every guess in `history`.
+    fn recompute_candidates(&mut self, words: &WordList) {
+        let word_len = self.word_len;
+        self.candidates = (0..words.count_with_length(word_len)).collect();
+        for &(letter, ref idxs) in &self.history {
+            let mask = idxs.iter().fold(0u32, |m, &i| m | (1 << i));
+            self.candidates.retain(|&idx| {
+                words.position_mask(Word { len: word_len, idx }, letter) == mask
+            });
+        }
+    }
+
+    /// Whether end-of-input was seen while waiting for an answer.
+    fn quit_requested(&self) -> bool { self.quit }
+
+    /// The single word consistent with every answer given so far, if the
+    /// guesses have narrowed `candidates` down to exactly one. Returns
+    /// `None` both when more than one candidate remains and when none do
+    /// (the latter meaning the answers are inconsistent with every word in
+    /// the dictionary, e.g. because the real opponent's word isn't in it).
+    fn deduced_word(&self) -> Option<Word> {
+        if self.candidates.len() == 1 {
+            self.candidates.iter().next().map(|&idx| Word { len: self.word_len, idx })
+        } else {
+            None
+        }
+    }
+
+    /// Pick the next letter to suggest: whichever not-yet-guessed letter
+    /// appears in the most remaining candidates, the same heuristic
+    /// `EpicStrategy` uses. Returns `None` once a single candidate remains
+    /// or every letter has already been guessed.
+    fn suggest_letter(&self, words: &WordList) -> Option<u8> {
+        if self.candidates.len() <= 1 {
+            return None;
+        }
+        let word_len = self.word_len;
+        let guessed: HashSet<u8> = self.history.iter().map(|&(letter, _)| letter).collect();
+        (b'a'..=b'z')
+            .filter(|c| !guessed.contains(c))
+            .max_by_key(|&c| {
+                self.candidates.iter()
+                    .filter(|&&idx| words.position_mask(Word { len: word_len, idx }, c) != 0)
+                    .count()
+            })
+    }
+}
+
+impl Executioner for InteractiveExecutioner {
+    fn init(word: Word, words: &WordList) -> Self {
+        Self::new(word.len, words)
+    }
+
+    fn guess(&mut self, words: &WordList, letter: u8, idxs: &mut Vec<u8>) {
+        loop {
+            self.print_state();
+            println!("  suggested guess: '{}'", letter as char);
+            print!("  where does it appear (e.g. \"1 3\"), or 'undo'/'candidates'? ");
+            io::stdout().flush().unwrap();
+
+            let line = match read_interactive_line() {
+                Some(line) => line,
+                None => {
+                    idxs.clear();
+                    self.quit = true;
+                    return;
+                }
+            };
+
+            match line.trim() {
+                "undo" => match self.history.pop() {
+                    Some((prev_letter, prev_idxs)) => {
+                        if prev_idxs.is_empty() {
+                            self.wrong_guesses -= 1;
+                        } else {
+                            for &i in &prev_idxs {
+                                self.revealed[i as usize] = None;
+                            }
+                        }
+                        self.recompute_candidates(words);
+                        println!("  undid guess of '{}'.", prev_letter as char);
+                    }
+                    None => println!("  nothing to undo."),
+                },
+                "candidates" => {
+                    println!("  {} candidate(s) remaining:", self.candidates.len());
+                    for &idx in &self.candidates {
+                        let word = Word { len: self.word_len, idx };
+                        println!("    {}", str::from_utf8(words.get(word)).unwrap());
+                    }
+                }
+                response => {
+                    idxs.clear();
+                    let mut valid = true;
+                    for tok in response.split_whitespace() {
+                        match tok.parse::<u8>() {
+                            Ok(pos) if (pos as usize) < self.revealed.len() => idxs.push(pos),
+                            _ => {
+                                println!("  expected positions between 0 and {}.", self.word_len - 1);
+                                valid = false;
+                                break;
+                            }
+                        }
+                    }
+                    if !valid {
+                        continue;
+                    }
+
+                    if idxs.is_empty() {
+                        self.wrong_guesses += 1;
+                    } else {
+                        for &i in idxs.iter() {
+                            self.revealed[i as usize] = Some(letter);
+                        }
+                    }
+                    self.history.push((letter, idxs.clone()));
+
+                    let word_len = self.word_len;
+                    let mask = idxs.iter().fold(0u32, |m, &i| m | (1 << i));
+                    self.candidates.retain(|&idx| {
+                        words.position_mask(Word { len: word_len, idx }, letter) == mask
+                    });
+                    return;
+                }
+            }
+        }
+    }
+
+    fn word_len(&self) -> u8 { self.word_len }
+    fn wrong_guesses(&self) -> usize { self.wrong_guesses }
+}
+
+/// Read one line of interactive input. The word list itself is read from
+/// stdin (see `main`), so once that's been consumed, prompts are read from
+/// the controlling terminal instead, falling back to stdin if there is none.
+/// Returns `None` on end-of-input.
+fn read_interactive_line() -> Option<String> {
+    let mut line = String::new();
+    let bytes_read = match std::fs::File::open("/dev/tty") {
+        Ok(tty) => io::BufReader::new(tty).read_line(&mut line).ok()?,
+        Err(_) => io::stdin().read_line(&mut line).ok()?,
+    };
+    if bytes_read == 0 { None } else { Some(line) }
+}
+
+/// Run an interactive REPL that helps a human win a real game of hangman,
+/// using the same most-frequent-letter heuristic as `EpicStrategy` to
+/// suggest a letter at each step.
+///
+/// This drives `InteractiveExecutioner` directly rather than through the
+/// `Strategy`/`Executioner` split the benchmarked strategies use: `Strategy`
+/// impls keep their own candidate set, which would desync from the
+/// executioner's the moment `undo` rewinds a guess mid-game. Since
+/// `InteractiveExecutioner` is the only place that set can live consistently,
+/// it also picks the suggested letter.
+fn run_interactive_solver(words: &WordList) {
+    println!("Interactive hangman solver. Enter a word length to start a new game, or 'quit'.");
+
+    loop {
+        print!("new game> word length: ");
+        io::stdout().flush().unwrap();
+
+        let line = match read_interactive_line() {
+            Some(line) => line,
+            None => break,
+        };
+        if line.trim() == "quit" {
+            break;
+        }
+
+        let word_len: u8 = match line.trim().parse() {
+            Ok(len) if 0 < len && len < MAX_WORD_LEN => len,
+            _ => {
+                println!("  expected a word length between 1 and {}.", MAX_WORD_LEN - 1);
+                continue;
+            }
+        };
+        if words.count_with_length(word_len) == 0 {
+            println!("  no words of that length.");
+            continue;
+        }
+
+        let mut exec = InteractiveExecutioner::new(word_len, words);
+        let mut idxs_buf = Vec::new();
+        while let Some(guess) = exec.suggest_letter(words) {
+            exec.guess(words, guess, &mut idxs_buf);
+            if exec.quit_requested() {
+                return;
+            }
+        }
+        match exec.deduced_word() {
+            Some(word) => println!(
+                "  it's \"{}\" -- solved in {} wrong guess(es).",
+                str::from_utf8(words.get(word)).unwrap(),
+                exec.wrong_guesses(),
+            ),
+            None => println!(
+                "  no word in the dictionary is consistent with those answers; check your input (try 'undo')."
+            ),
+        }
+    }
+}
+
 /// Represents a particular way to play the game.
 trait Strategy {
     fn play<E: Executioner, R: Rng>(
@@ -170,6 +498,7 @@ trait Strategy {
 }
 
 /// A strategy that guesses letters in a random order.
+#[derive(Clone)]
 struct RandomStrategy {
     guesses: Vec<u8>,
     idxs_buf: Vec<u8>,
@@ -207,6 +536,7 @@ impl Strategy for RandomStrategy {
 }
 
 /// A strategy that guesses letters in order of their frequency.
+#[derive(Clone)]
 struct SimpleStrategy {
     idxs_buf: Vec<u8>,
 }
@@ -245,6 +575,7 @@ impl Strategy for SimpleStrategy {
 ///
 /// Specifically, it guesses whichever letter appears most often in
 /// the set of remaining possible words.
+#[derive(Clone)]
 struct EpicStrategy {
     candidates: HashSet<usize>,
     remaining_letters: Vec<u8>,
@@ -280,12 +611,10 @@ impl Strategy for EpicStrategy {
             // Identify the frequencies with which each letter appears in the candidate words.
             let mut letter_frequencies: [usize; 26] = [0; 26];
             for &word in self.candidates.iter() {
-                // Make sure not to double-count letters.
-                let mut letter_appearances: [bool; 26] = [false; 26];
-                for letter in words.get(Word { len: word_len, idx: word }) {
-                    let idx = (letter - b'a') as usize;
-                    if !mem::replace(&mut letter_appearances[idx], true) {
-                        letter_frequencies[idx] += 1;
+                let word = Word { len: word_len, idx: word };
+                for (a, freq) in letter_frequencies.iter_mut().enumerate() {
+                    if words.position_mask(word, b'a' + a as u8) != 0 {
+                        *freq += 1;
                     }
                 }
             }
@@ -299,15 +628,73 @@ impl Strategy for EpicStrategy {
             self.remaining_letters.swap_remove(i);
             executioner.guess(words, guess, &mut self.idxs_buf);
 
-            // Keep only the candidates that have that letter in only the specified positions.
-            let idxs_buf = &mut self.idxs_buf;
+            // Keep only the candidates whose positions for `guess` match the response exactly.
+            let response_mask = self.idxs_buf.iter().fold(0u32, |mask, &i| mask | (1 << i));
             self.candidates.retain(|&word| {
                 let word = Word { len: word_len, idx: word };
-                words.get(word).iter().enumerate().all(|(i, &letter)| {
-                    let does_match   = letter == guess;
-                    let should_match = idxs_buf.contains(&(i as u8));
-                    does_match == should_match
+                words.position_mask(word, guess) == response_mask
+            });
+        }
+
+        assert_eq!(self.candidates.len(), 1);
+    }
+}
+
+/// A strategy that, like `EpicStrategy`, maintains a candidate set of words
+/// consistent with the answers given so far, but instead of guessing the
+/// most-frequent letter it guesses whichever letter maximizes the expected
+/// information gained from the executioner's response.
+#[derive(Clone)]
+struct EntropyStrategy {
+    candidates: HashSet<usize>,
+    remaining_letters: Vec<u8>,
+    idxs_buf: Vec<u8>,
+}
+
+impl EntropyStrategy {
+    fn new() -> Self {
+        Self {
+            candidates: HashSet::new(),
+            remaining_letters: Vec::new(),
+            idxs_buf: Vec::new(),
+        }
+    }
+}
+
+impl Strategy for EntropyStrategy {
+    fn play<E: Executioner, R: Rng>(
+        &mut self,
+        executioner: &mut E,
+        words: &WordList,
+        _rng: &mut R,
+    ) {
+        self.remaining_letters.clear();
+        self.remaining_letters.extend(b'a'..=b'z');
+
+        let word_len = executioner.word_len();
+        self.candidates.clear();
+        self.candidates.extend(0..words.count_with_length(word_len));
+
+        while self.candidates.len() > 1 {
+            // For each unguessed letter, partition the candidates by the set of
+            // positions that letter would reveal, and score the letter by the
+            // Shannon entropy of that partition.
+            let (i, guess) = self.remaining_letters
+                .iter().copied().enumerate()
+                .max_by(|&(_, a), &(_, b)| {
+                    entropy(&self.candidates, words, word_len, a)
+                        .partial_cmp(&entropy(&self.candidates, words, word_len, b))
+                        .unwrap()
                 })
+                .unwrap();
+            self.remaining_letters.swap_remove(i);
+            executioner.guess(words, guess, &mut self.idxs_buf);
+
+            // Keep only the candidates whose positions for `guess` match the response exactly.
+            let response_mask = self.idxs_buf.iter().fold(0u32, |mask, &i| mask | (1 << i));
+            self.candidates.retain(|&word| {
+                let word = Word { len: word_len, idx: word };
+                words.position_mask(word, guess) == response_mask
             });
         }
 
@@ -315,29 +702,165 @@ impl Strategy for EpicStrategy {
     }
 }
 
-fn describe_strategy<S, R>(desc: &str, strategy: &mut S, words: &WordList, rng: &mut R)
+/// Compute the Shannon entropy, in bits, of the partition of `candidates`
+/// induced by the response `letter` would get from the executioner.
+fn entropy(candidates: &HashSet<usize>, words: &WordList, word_len: u8, letter: u8) -> f64 {
+    let mut buckets: HashMap<u32, usize> = HashMap::new();
+    for &word in candidates {
+        let word = Word { len: word_len, idx: word };
+        let mask = words.position_mask(word, letter);
+        *buckets.entry(mask).or_insert(0) += 1;
+    }
+    let total = candidates.len() as f64;
+    buckets.values()
+        .map(|&n| {
+            let p = n as f64 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// At or below this many remaining lives, `BudgetStrategy` prefers a guess
+/// that's guaranteed safe over the most-frequent letter.
+const LOW_LIVES_THRESHOLD: usize = 2;
+
+/// A strategy that plays under a wrong-guess budget ("lives", as in classic
+/// hangman). Like `EpicStrategy` it guesses whichever unguessed letter
+/// appears in the most candidates, but once few lives remain it instead
+/// prefers any letter guaranteed to appear in every remaining candidate —
+/// trading optimal information for survival.
+#[derive(Clone)]
+struct BudgetStrategy {
+    max_wrong_guesses: usize,
+    candidates: HashSet<usize>,
+    remaining_letters: Vec<u8>,
+    idxs_buf: Vec<u8>,
+}
+
+impl BudgetStrategy {
+    fn new(max_wrong_guesses: usize) -> Self {
+        Self {
+            max_wrong_guesses,
+            candidates: HashSet::new(),
+            remaining_letters: Vec::new(),
+            idxs_buf: Vec::new(),
+        }
+    }
+}
+
+impl Strategy for BudgetStrategy {
+    fn play<E: Executioner, R: Rng>(
+        &mut self,
+        executioner: &mut E,
+        words: &WordList,
+        _rng: &mut R,
+    ) {
+        self.remaining_letters.clear();
+        self.remaining_letters.extend(b'a'..=b'z');
+
+        let word_len = executioner.word_len();
+        self.candidates.clear();
+        self.candidates.extend(0..words.count_with_length(word_len));
+
+        while self.candidates.len() > 1 {
+            let mut letter_frequencies: [usize; 26] = [0; 26];
+            for &word in self.candidates.iter() {
+                let word = Word { len: word_len, idx: word };
+                for (a, freq) in letter_frequencies.iter_mut().enumerate() {
+                    if words.position_mask(word, b'a' + a as u8) != 0 {
+                        *freq += 1;
+                    }
+                }
+            }
+
+            let remaining_lives = self.max_wrong_guesses.saturating_sub(executioner.wrong_guesses());
+            let guess = if remaining_lives <= LOW_LIVES_THRESHOLD {
+                // Prefer a letter guaranteed to be in the word over a riskier,
+                // more discriminating one, falling back if none is guaranteed.
+                self.remaining_letters.iter().copied()
+                    .find(|&c| letter_frequencies[(c - b'a') as usize] == self.candidates.len())
+                    .unwrap_or_else(|| {
+                        self.remaining_letters.iter().copied()
+                            .max_by_key(|&c| letter_frequencies[(c - b'a') as usize])
+                            .unwrap()
+                    })
+            } else {
+                self.remaining_letters.iter().copied()
+                    .max_by_key(|&c| letter_frequencies[(c - b'a') as usize])
+                    .unwrap()
+            };
+
+            let i = self.remaining_letters.iter().position(|&c| c == guess).unwrap();
+            self.remaining_letters.swap_remove(i);
+            executioner.guess(words, guess, &mut self.idxs_buf);
+
+            let response_mask = self.idxs_buf.iter().fold(0u32, |mask, &i| mask | (1 << i));
+            self.candidates.retain(|&word| {
+                let word = Word { len: word_len, idx: word };
+                words.position_mask(word, guess) == response_mask
+            });
+        }
+
+        assert_eq!(self.candidates.len(), 1);
+    }
+}
+
+/// Run `strategy` against every word in `words`, using a fresh clone of the
+/// strategy (and a fresh `E`) per word so that words can be played out in
+/// parallel. `num_threads` optionally overrides the size of the thread pool
+/// used; `None` uses rayon's global pool. If `max_wrong_guesses` is given,
+/// also reports the fraction of words solved within that many lives.
+fn describe_strategy<S, E>(
+    desc: &str,
+    strategy: &S,
+    words: &WordList,
+    num_threads: Option<usize>,
+    max_wrong_guesses: Option<usize>,
+)
     where
-        S: Strategy,
-        R: Rng
+        S: Strategy + Clone + Sync,
+        E: Executioner,
 {
     let start = Instant::now();
     println!("Strategy '{}':", desc);
 
-    let mut scores = Vec::<(Word, usize)>::new();
+    let word_list: Vec<Word> = words.iter().collect();
+
+    let play_all = || {
+        word_list.par_iter()
+            .map(|&word| {
+                let mut strategy = strategy.clone();
+                let mut rng = rand::thread_rng();
+                let mut exec = E::init(word, words);
+                strategy.play(&mut exec, words, &mut rng);
+                (word, exec.wrong_guesses())
+            })
+            .collect::<Vec<(Word, usize)>>()
+    };
+
+    let mut scores = match num_threads {
+        Some(num_threads) => {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(num_threads)
+                .build()
+                .unwrap()
+                .install(play_all)
+        }
+        None => play_all(),
+    };
 
-    let mut total_wrong_guesses = 0;
-    let mut total_words = 0;
-    for word in words.iter() {
-        let mut exec = HonestExecutioner::init(word, words);
-        strategy.play(&mut exec, words, rng);
-        scores.push((word, exec.wrong_guesses()));
-        total_wrong_guesses += exec.wrong_guesses();
-        total_words += 1;
-    }
+    let total_words = scores.len();
+    let total_wrong_guesses: usize = scores.iter().map(|(_, wrong_guesses)| wrong_guesses).sum();
 
     println!("  Average # of wrong guesses: {}",
              (total_wrong_guesses as f64) / (total_words as f64));
 
+    if let Some(budget) = max_wrong_guesses {
+        let solved = scores.iter().filter(|&&(_, wrong_guesses)| wrong_guesses <= budget).count();
+        println!("  Solve rate within {} wrong guess(es): {:.1}%",
+                 budget, 100.0 * (solved as f64) / (total_words as f64));
+    }
+
     scores.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
 
     println!("  Sorted words by guessability.");
@@ -369,9 +892,25 @@ fn main() {
         words.insert(&line);
     }
 
-    let mut rng = rand::thread_rng();
+    if std::env::args().any(|arg| arg == "--interactive") {
+        run_interactive_solver(&words);
+        return;
+    }
+
+    // Classic hangman gives the guesser six wrong guesses before they lose.
+    const CLASSIC_LIVES: usize = 6;
 
-    describe_strategy("random", &mut RandomStrategy::new(), &words, &mut rng);
-    describe_strategy("simple", &mut SimpleStrategy::new(), &words, &mut rng);
-    describe_strategy("epic", &mut EpicStrategy::new(), &words, &mut rng);
+    describe_strategy::<_, HonestExecutioner>("random", &RandomStrategy::new(), &words, None, Some(CLASSIC_LIVES));
+    describe_strategy::<_, HonestExecutioner>("simple", &SimpleStrategy::new(), &words, None, Some(CLASSIC_LIVES));
+    describe_strategy::<_, HonestExecutioner>("epic", &EpicStrategy::new(), &words, None, Some(CLASSIC_LIVES));
+    describe_strategy::<_, HonestExecutioner>("entropy", &EntropyStrategy::new(), &words, None, Some(CLASSIC_LIVES));
+    describe_strategy::<_, HonestExecutioner>(
+        "budget", &BudgetStrategy::new(CLASSIC_LIVES), &words, None, Some(CLASSIC_LIVES),
+    );
+
+    // Check how `EpicStrategy` fares against an opponent who refuses to commit
+    // to a word and instead always answers as unhelpfully as possible.
+    describe_strategy::<_, AdversarialExecutioner>(
+        "epic-adversarial", &EpicStrategy::new(), &words, None, Some(CLASSIC_LIVES),
+    );
 }